@@ -1,35 +1,292 @@
 use rand::Rng;
 use rand_distr::{Distribution, Normal};
-use std::sync::atomic::{AtomicBool, Ordering};
+use std::fs::File;
+use std::io::{BufRead, BufReader, Write};
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
 use std::sync::{Arc, Mutex};
 use std::thread;
 use std::time::{Duration, Instant};
 
-// Hotkey for turning auto clicker on/off
-const RUNNING_TOGGLE_KEY: rdev::Key = rdev::Key::F8;
+// Hotkey for starting/stopping macro recording
+const RECORDING_TOGGLE_KEY: rdev::Key = rdev::Key::F9;
+// Hotkey for starting/stopping macro replay
+const REPLAY_TOGGLE_KEY: rdev::Key = rdev::Key::F10;
 
-// Time between clicks
-const CLICK_INTERVAL_MEAN_MS: f64 = 1200.0;
-const CLICK_INTERVAL_SD_MS: f64 = CLICK_INTERVAL_MEAN_MS / 6.0;
+// Where recorded macros are stored
+const MACRO_FILE_PATH: &str = "macro.jsonl";
+// How many times a macro is replayed (0 = loop forever)
+const REPLAY_LOOP_COUNT: u64 = 1;
+// Playback speed multiplier (2.0 = twice as fast)
+const REPLAY_SPEED: f64 = 1.0;
 
-// Time between click down & up
-const HOLD_DURATION_MEAN_MS: f64 = 85.0;
-const HOLD_DURATION_SD_MS: f64 = HOLD_DURATION_MEAN_MS / 6.0;
+// Where the runtime configuration is loaded from
+const CONFIG_FILE_PATH: &str = "config.toml";
 
-// Stop after moving mouse more than N pixels
-const MOVE_STOP_DISTANCE_PX: f64 = 16.0;
+// The two keys whose simultaneous press is collapsed into an emulated chord
+const CHORD_KEY_A: rdev::Key = rdev::Key::F6;
+const CHORD_KEY_B: rdev::Key = rdev::Key::F7;
+// Maximum gap between the two presses to count as a chord (moused's button2timeout)
+const BUTTON2TIMEOUT_MS: u64 = 200;
+
+// State of the two-button simultaneous-press recognizer, modelled after moused's
+// three-button emulation state machine.
+enum ChordState {
+    // Start state, neither chord key is being waited on
+    Start,
+    // One chord key went down and we are waiting for the other before the timeout
+    DelayedDown,
+    // Both keys went down in time, the emulated action has already fired
+    Combined,
+}
+
+struct Chord {
+    state: ChordState,
+    // Per-key press timestamps, like moused's buttonstate[].tv
+    press_times: [Option<Instant>; 2],
+    // Bumped every time we arm the timer, so a stale timer thread won't reset a newer press
+    epoch: u64,
+}
+
+// A named set of clicker tunables. Multiple profiles can be defined in the config file and
+// switched between at runtime via their `toggle_key`.
+#[derive(serde::Deserialize, Clone)]
+struct Profile {
+    name: String,
+    click_interval_mean_ms: f64,
+    click_interval_sd_ms: f64,
+    hold_duration_mean_ms: f64,
+    hold_duration_sd_ms: f64,
+    // Stop after moving mouse more than N pixels
+    move_stop_distance_px: f64,
+    // Button the clicker targets while this profile is active
+    target_button: rdev::Button,
+    // Key that selects this profile (and toggles the clicker when it is already active)
+    toggle_key: rdev::Key,
+}
+
+impl Profile {
+    fn params(&self) -> LiveParams {
+        return LiveParams {
+            click_interval_mean_ms: self.click_interval_mean_ms,
+            click_interval_sd_ms: self.click_interval_sd_ms,
+            hold_duration_mean_ms: self.hold_duration_mean_ms,
+            hold_duration_sd_ms: self.hold_duration_sd_ms,
+            move_stop_distance_px: self.move_stop_distance_px,
+        };
+    }
+}
+
+// The live, editable tunables of the active profile. Shared between the clicker thread and
+// the GUI so slider edits take effect on the clicker's next loop.
+#[derive(Clone, Copy)]
+struct LiveParams {
+    click_interval_mean_ms: f64,
+    click_interval_sd_ms: f64,
+    hold_duration_mean_ms: f64,
+    hold_duration_sd_ms: f64,
+    move_stop_distance_px: f64,
+}
+
+// Top-level config file layout: `[[profile]]` tables deserialize into `profiles`.
+#[derive(serde::Deserialize)]
+struct Config {
+    #[serde(rename = "profile")]
+    profiles: Vec<Profile>,
+}
+
+// Action fired when a bound key combo is pressed.
+#[derive(Clone)]
+enum Action {
+    ToggleRunning,
+    ToggleRecording,
+    ToggleReplay,
+    SwitchProfile(usize),
+}
+
+// Ordered set of keys currently held down, updated on every KeyPress/KeyRelease so that
+// key combinations can be matched and stuck-combo states avoided on key-up.
+struct Pressed {
+    keys: Vec<rdev::Key>,
+}
+
+impl Pressed {
+    // Record a key as held. Returns false for auto-repeat of an already-held key.
+    fn press(&mut self, key: rdev::Key) -> bool {
+        if self.keys.contains(&key) {
+            return false;
+        }
+        self.keys.push(key);
+        return true;
+    }
+
+    fn release(&mut self, key: rdev::Key) {
+        self.keys.retain(|k| *k != key);
+    }
+
+    // True when every key of `combo` is currently held.
+    fn are_pressed(&self, combo: &[rdev::Key]) -> bool {
+        return combo.iter().all(|key| self.keys.contains(key));
+    }
+}
+
+// A single recorded input event together with the time to wait before emitting it
+#[derive(serde::Serialize, serde::Deserialize, Clone)]
+struct RecordedEvent {
+    event_type: rdev::EventType,
+    delay_ms: f64,
+}
 
 struct Data {
     is_running: AtomicBool,
+    is_recording: AtomicBool,
+    is_replaying: AtomicBool,
     initial_mouse_pos: Mutex<Option<(f64, f64)>>,
+    // Events captured in the current recording session
+    recorded_events: Mutex<Vec<RecordedEvent>>,
+    // Instant of the previously recorded event, used to compute inter-event deltas
+    record_last_instant: Mutex<Option<Instant>>,
+    // Button the clicker currently targets, toggled by the emulated chord
+    target_button: Mutex<rdev::Button>,
+    // Simultaneous-press recognizer for the two chord keys
+    chord: Mutex<Chord>,
+    // Configured profiles and an index into it selecting the active one
+    profiles: Vec<Profile>,
+    active_profile: AtomicUsize,
+    // Keys currently held, and the registry of combo -> action bindings
+    pressed: Mutex<Pressed>,
+    bindings: Vec<(Vec<rdev::Key>, Action)>,
+    // Live tunables and a generation counter the clicker watches for changes
+    params: Mutex<LiveParams>,
+    params_generation: AtomicUsize,
+    // Live stats surfaced in the GUI
+    click_count: AtomicU64,
+    last_click_interval_ms: Mutex<f64>,
+    last_hold_duration_ms: Mutex<f64>,
 }
 
 impl Data {
-    fn new() -> Self {
+    fn new(config: Config) -> Self {
+        let target_button = config.profiles[0].target_button;
+        let params = config.profiles[0].params();
+
+        // Build the binding registry: one combo per profile plus the record/replay and
+        // a global Ctrl+Shift+F8 toggle that overrides the single-key profile combos.
+        let mut bindings: Vec<(Vec<rdev::Key>, Action)> = Vec::new();
+        for (index, profile) in config.profiles.iter().enumerate() {
+            bindings.push((vec![profile.toggle_key], Action::SwitchProfile(index)));
+        }
+        bindings.push((vec![RECORDING_TOGGLE_KEY], Action::ToggleRecording));
+        bindings.push((vec![REPLAY_TOGGLE_KEY], Action::ToggleReplay));
+        bindings.push((
+            vec![
+                rdev::Key::ControlLeft,
+                rdev::Key::ShiftLeft,
+                rdev::Key::F8,
+            ],
+            Action::ToggleRunning,
+        ));
+
         return Self {
             is_running: AtomicBool::new(false),
+            is_recording: AtomicBool::new(false),
+            is_replaying: AtomicBool::new(false),
             initial_mouse_pos: Mutex::new(None),
+            recorded_events: Mutex::new(Vec::new()),
+            record_last_instant: Mutex::new(None),
+            target_button: Mutex::new(target_button),
+            chord: Mutex::new(Chord {
+                state: ChordState::Start,
+                press_times: [None, None],
+                epoch: 0,
+            }),
+            profiles: config.profiles,
+            active_profile: AtomicUsize::new(0),
+            pressed: Mutex::new(Pressed { keys: Vec::new() }),
+            bindings,
+            params: Mutex::new(params),
+            params_generation: AtomicUsize::new(0),
+            click_count: AtomicU64::new(0),
+            last_click_interval_ms: Mutex::new(0.0),
+            last_hold_duration_ms: Mutex::new(0.0),
+        };
+    }
+
+    fn get_params(&self) -> LiveParams {
+        return *self.params.lock().unwrap();
+    }
+
+    // Replace the live tunables (e.g. from a GUI slider) and signal the clicker to rebuild.
+    fn set_params(&self, params: LiveParams) {
+        *self.params.lock().unwrap() = params;
+        self.params_generation.fetch_add(1, Ordering::SeqCst);
+    }
+
+    fn get_target_button(&self) -> rdev::Button {
+        return *self.target_button.lock().unwrap();
+    }
+
+    // Switch the active profile; the clicker thread picks up the new distributions on its next loop.
+    fn set_active_profile(&self, index: usize, reason: &str) {
+        self.active_profile.store(index, Ordering::SeqCst);
+        *self.target_button.lock().unwrap() = self.profiles[index].target_button;
+        self.set_params(self.profiles[index].params());
+        println!("Profile:      {} ({reason})", self.profiles[index].name);
+    }
+
+    // Update the pressed-keys tracker and fire the most specific matching combo, if any.
+    // Returns true when a binding was fired (so the key is not also recorded as raw input).
+    fn handle_key_press(&self, key: rdev::Key) -> bool {
+        let action = {
+            let mut pressed = self.pressed.lock().unwrap();
+            if !pressed.press(key) {
+                // Auto-repeat of a held key, don't re-fire the combo
+                return false;
+            }
+            // Longest matching combo wins, so Ctrl+Shift+F8 beats a bare F8 binding
+            self.bindings
+                .iter()
+                .filter(|(combo, _)| pressed.are_pressed(combo))
+                .max_by_key(|(combo, _)| combo.len())
+                .map(|(_, action)| action.clone())
         };
+
+        match action {
+            Some(action) => {
+                self.fire_action(action);
+                return true;
+            }
+            None => return false,
+        }
+    }
+
+    fn handle_key_release(&self, key: rdev::Key) {
+        self.pressed.lock().unwrap().release(key);
+    }
+
+    fn fire_action(&self, action: Action) {
+        match action {
+            Action::ToggleRunning => {
+                let new_running = !self.get_running();
+                self.set_running(new_running, "combo pressed");
+            }
+            Action::ToggleRecording => {
+                let new_recording = !self.get_recording();
+                self.set_recording(new_recording, "combo pressed");
+            }
+            Action::ToggleReplay => {
+                let new_replaying = !self.get_replaying();
+                self.set_replaying(new_replaying, "combo pressed");
+            }
+            Action::SwitchProfile(index) => {
+                if index == self.active_profile.load(Ordering::SeqCst) {
+                    let new_running = !self.get_running();
+                    self.set_running(new_running, "combo pressed");
+                } else {
+                    self.set_active_profile(index, "combo pressed");
+                    self.set_running(true, "profile switched");
+                }
+            }
+        }
     }
 
     fn get_running(&self) -> bool {
@@ -48,18 +305,162 @@ impl Data {
         let new_running_str = if new_running { "ON " } else { "OFF" };
         println!("Auto clicker: {new_running_str} ({reason})",);
     }
+
+    fn get_recording(&self) -> bool {
+        return self.is_recording.load(Ordering::SeqCst);
+    }
+
+    fn set_recording(&self, new_recording: bool, reason: &str) {
+        if new_recording == self.get_recording() {
+            return;
+        }
+        if new_recording {
+            self.recorded_events.lock().unwrap().clear();
+            *self.record_last_instant.lock().unwrap() = None;
+        }
+        self.is_recording.store(new_recording, Ordering::SeqCst);
+
+        let new_recording_str = if new_recording { "ON " } else { "OFF" };
+        println!("Recording:    {new_recording_str} ({reason})");
+
+        if !new_recording {
+            // Recording stopped, flush the captured events to disk
+            let events = self.recorded_events.lock().unwrap();
+            if let Err(error) = save_macro(MACRO_FILE_PATH, &events) {
+                eprintln!("Error saving macro to {MACRO_FILE_PATH}: {error}");
+            } else {
+                println!("Saved {} events to {MACRO_FILE_PATH}", events.len());
+            }
+        }
+    }
+
+    fn get_replaying(&self) -> bool {
+        return self.is_replaying.load(Ordering::SeqCst);
+    }
+
+    fn set_replaying(&self, new_replaying: bool, reason: &str) {
+        if new_replaying == self.get_replaying() {
+            return;
+        }
+        self.is_replaying.store(new_replaying, Ordering::SeqCst);
+
+        let new_replaying_str = if new_replaying { "ON " } else { "OFF" };
+        println!("Replaying:    {new_replaying_str} ({reason})");
+    }
+
+    // Append an event to the current recording, computing its delay from the previous one
+    fn record_event(&self, event_type: rdev::EventType) {
+        let now = Instant::now();
+        let mut last_guard = self.record_last_instant.lock().unwrap();
+        let delay_ms = match *last_guard {
+            Some(last) => (now - last).as_secs_f64() * 1e3,
+            None => 0.0,
+        };
+        *last_guard = Some(now);
+
+        self.recorded_events.lock().unwrap().push(RecordedEvent {
+            event_type,
+            delay_ms,
+        });
+    }
+
+    // Feed a chord-key press into the state machine, arming a commit timer on the first press.
+    fn chord_key_press(self: &Arc<Self>, index: usize) {
+        let mut chord = self.chord.lock().unwrap();
+        let now = Instant::now();
+        chord.press_times[index] = Some(now);
+
+        match chord.state {
+            ChordState::Start => {
+                // First half of the chord: move to the delayed-down state and arm the timer
+                chord.state = ChordState::DelayedDown;
+                chord.epoch += 1;
+                let epoch = chord.epoch;
+                drop(chord);
+
+                let data = Arc::clone(self);
+                thread::spawn(move || {
+                    thread::sleep(Duration::from_millis(BUTTON2TIMEOUT_MS));
+                    data.chord_timeout(epoch);
+                });
+            }
+            ChordState::DelayedDown => {
+                // Second half arrived: only a chord if the other key is still within the timeout
+                let other = 1 - index;
+                let in_time = match chord.press_times[other] {
+                    Some(other_time) => {
+                        now.duration_since(other_time) <= Duration::from_millis(BUTTON2TIMEOUT_MS)
+                    }
+                    None => false,
+                };
+                if in_time {
+                    chord.state = ChordState::Combined;
+                    drop(chord);
+                    self.fire_emulated_chord();
+                }
+            }
+            ChordState::Combined => {}
+        }
+    }
+
+    // The recognizer returns to the start state once both chord keys are released.
+    fn chord_key_release(&self, index: usize) {
+        let mut chord = self.chord.lock().unwrap();
+        chord.press_times[index] = None;
+        if chord.press_times.iter().all(|t| t.is_none()) {
+            chord.state = ChordState::Start;
+        }
+    }
+
+    // Called by the armed timer: if the second key did not arrive in time, drop back to the
+    // start state.
+    fn chord_timeout(&self, epoch: u64) {
+        let mut chord = self.chord.lock().unwrap();
+        if chord.epoch != epoch {
+            return;
+        }
+        if let ChordState::DelayedDown = chord.state {
+            // The second key never came. The lone press fires no action and is not passed on
+            // to the bindings or to a recording.
+            chord.state = ChordState::Start;
+        }
+    }
+
+    // The emulated chord action: swap the clicker's target button between left and right.
+    // The active profile and its tunables stay as they are.
+    fn fire_emulated_chord(&self) {
+        let mut button = self.target_button.lock().unwrap();
+        *button = match *button {
+            rdev::Button::Left => rdev::Button::Right,
+            _ => rdev::Button::Left,
+        };
+        println!("Emulated chord: target button -> {:?}", *button);
+    }
 }
 
 fn main() {
+    let config = load_config(CONFIG_FILE_PATH);
+
     println!("==== AUTO CLICKER ====");
-    println!("Toggle on/off hotkey: {RUNNING_TOGGLE_KEY:?}");
-    println!("Click interval:       {CLICK_INTERVAL_MEAN_MS} ms (SD = {CLICK_INTERVAL_SD_MS} ms)");
-    println!("Hold duration:        {HOLD_DURATION_MEAN_MS} ms (SD = {HOLD_DURATION_SD_MS} ms)");
-    println!("Mouse move threshold: {MOVE_STOP_DISTANCE_PX} px");
+    println!("Record macro hotkey:  {RECORDING_TOGGLE_KEY:?}");
+    println!("Replay macro hotkey:  {REPLAY_TOGGLE_KEY:?}");
+    println!("Chord keys:           {CHORD_KEY_A:?} + {CHORD_KEY_B:?} (swap target button)");
+    println!("Global toggle combo:  ControlLeft + ShiftLeft + F8");
+    println!("Profiles:");
+    for profile in &config.profiles {
+        println!(
+            "  {:?} -> {:<8} (click {} ms, hold {} ms, {:?})",
+            profile.toggle_key,
+            profile.name,
+            profile.click_interval_mean_ms,
+            profile.hold_duration_mean_ms,
+            profile.target_button,
+        );
+    }
     println!("");
     println!("==== LOG ====");
 
-    let data = Arc::new(Data::new());
+    let data = Arc::new(Data::new(config));
 
     {
         // Spawn clicker thread
@@ -67,24 +468,33 @@ fn main() {
         thread::spawn(move || clicker_thread(data));
     }
 
+    {
+        // Spawn player thread
+        let data = Arc::clone(&data);
+        thread::spawn(move || player_thread(data));
+    }
+
     {
         // Spawn listener thread
         let data = Arc::clone(&data);
         thread::spawn(move || listener_thread(data));
     }
 
+    // The GUI (when enabled) owns the main thread; otherwise just keep it alive forever
+    #[cfg(feature = "gui")]
+    run_gui(Arc::clone(&data));
+
+    #[cfg(not(feature = "gui"))]
     loop {
-        // Keep main thread alive forever
         thread::park();
     }
 }
 
 fn clicker_thread(data: Arc<Data>) {
     let mut rng = rand::rng();
-    let click_distribution = Normal::new(CLICK_INTERVAL_MEAN_MS, CLICK_INTERVAL_SD_MS)
-        .expect("Invalid normal distribution");
-    let hold_distribution = Normal::new(HOLD_DURATION_MEAN_MS, HOLD_DURATION_SD_MS)
-        .expect("Invalid normal distribution");
+
+    let mut generation = data.params_generation.load(Ordering::SeqCst);
+    let (mut click_distribution, mut hold_distribution) = build_distributions(&data.get_params());
 
     loop {
         if !data.get_running() {
@@ -93,16 +503,150 @@ fn clicker_thread(data: Arc<Data>) {
             continue;
         }
 
+        // Rebuild the distributions whenever the profile changes or a slider edits the params
+        let current_generation = data.params_generation.load(Ordering::SeqCst);
+        if current_generation != generation {
+            generation = current_generation;
+            let (click, hold) = build_distributions(&data.get_params());
+            click_distribution = click;
+            hold_distribution = hold;
+        }
+
         let click_interval_ms = sample_positive(&click_distribution, &mut rng);
         let hold_duration_ms = sample_positive(&hold_distribution, &mut rng);
 
-        send_event(&rdev::EventType::ButtonPress(rdev::Button::Left));
+        // Publish the sampled values and bump the counter for the GUI
+        *data.last_click_interval_ms.lock().unwrap() = click_interval_ms;
+        *data.last_hold_duration_ms.lock().unwrap() = hold_duration_ms;
+        data.click_count.fetch_add(1, Ordering::SeqCst);
+
+        let button = data.get_target_button();
+        send_event(&rdev::EventType::ButtonPress(button));
         adaptive_wait(Duration::from_secs_f64(hold_duration_ms * 1e-3));
-        send_event(&rdev::EventType::ButtonRelease(rdev::Button::Left));
+        send_event(&rdev::EventType::ButtonRelease(button));
         adaptive_wait(Duration::from_secs_f64(click_interval_ms * 1e-3));
     }
 }
 
+fn player_thread(data: Arc<Data>) {
+    loop {
+        if !data.get_replaying() {
+            // Not replaying, wait a bit
+            thread::sleep(Duration::from_millis(250));
+            continue;
+        }
+
+        let events = match load_macro(MACRO_FILE_PATH) {
+            Ok(events) => events,
+            Err(error) => {
+                eprintln!("Error loading macro from {MACRO_FILE_PATH}: {error}");
+                data.set_replaying(false, "load failed");
+                continue;
+            }
+        };
+
+        let mut iteration: u64 = 0;
+        while data.get_replaying() {
+            for event in &events {
+                if !data.get_replaying() {
+                    break;
+                }
+                // Preserve the recorded timing, scaled by the playback speed
+                adaptive_wait(Duration::from_secs_f64(event.delay_ms * 1e-3 / REPLAY_SPEED));
+                send_event(&event.event_type);
+            }
+
+            iteration += 1;
+            if REPLAY_LOOP_COUNT != 0 && iteration >= REPLAY_LOOP_COUNT {
+                data.set_replaying(false, "replay finished");
+                break;
+            }
+        }
+    }
+}
+
+fn save_macro(path: &str, events: &[RecordedEvent]) -> serde_json::Result<()> {
+    let mut file = File::create(path)?;
+    for event in events {
+        let line = serde_json::to_string(event)?;
+        writeln!(file, "{line}")?;
+    }
+    return Ok(());
+}
+
+fn load_macro(path: &str) -> serde_json::Result<Vec<RecordedEvent>> {
+    let file = File::open(path)?;
+    let reader = BufReader::new(file);
+
+    let mut events = Vec::new();
+    for line in reader.lines() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        events.push(serde_json::from_str(&line)?);
+    }
+    return Ok(events);
+}
+
+fn build_distributions(params: &LiveParams) -> (Normal<f64>, Normal<f64>) {
+    let click_distribution =
+        Normal::new(params.click_interval_mean_ms, params.click_interval_sd_ms)
+            .expect("Invalid normal distribution");
+    let hold_distribution = Normal::new(params.hold_duration_mean_ms, params.hold_duration_sd_ms)
+        .expect("Invalid normal distribution");
+    return (click_distribution, hold_distribution);
+}
+
+fn load_config(path: &str) -> Config {
+    let config = match std::fs::read_to_string(path) {
+        Ok(text) => match toml::from_str::<Config>(&text) {
+            Ok(config) => config,
+            Err(error) => {
+                eprintln!("Error parsing {path}: {error}, using default profiles");
+                default_config()
+            }
+        },
+        Err(_) => {
+            println!("No {path} found, using default profiles");
+            default_config()
+        }
+    };
+
+    if config.profiles.is_empty() {
+        eprintln!("Config has no profiles, using default profiles");
+        return default_config();
+    }
+    return config;
+}
+
+fn default_config() -> Config {
+    return Config {
+        profiles: vec![
+            Profile {
+                name: "default".to_string(),
+                click_interval_mean_ms: 1200.0,
+                click_interval_sd_ms: 1200.0 / 6.0,
+                hold_duration_mean_ms: 85.0,
+                hold_duration_sd_ms: 85.0 / 6.0,
+                move_stop_distance_px: 16.0,
+                target_button: rdev::Button::Left,
+                toggle_key: rdev::Key::F8,
+            },
+            Profile {
+                name: "fast".to_string(),
+                click_interval_mean_ms: 120.0,
+                click_interval_sd_ms: 120.0 / 6.0,
+                hold_duration_mean_ms: 40.0,
+                hold_duration_sd_ms: 40.0 / 6.0,
+                move_stop_distance_px: 16.0,
+                target_button: rdev::Button::Left,
+                toggle_key: rdev::Key::F4,
+            },
+        ],
+    };
+}
+
 fn sample_positive<R: Rng>(distribution: &Normal<f64>, rng: &mut R) -> f64 {
     for _ in 0..10 {
         let value = distribution.sample(rng);
@@ -146,13 +690,119 @@ fn listener_thread(data: Arc<Data>) {
     }
 }
 
-fn handle_event(event: rdev::Event, data: &Data) {
+// Optional egui/eframe control panel. Reads the same `Data` the clicker and listener threads
+// share, so state updates live and slider edits feed straight back into the distributions.
+#[cfg(feature = "gui")]
+fn run_gui(data: Arc<Data>) {
+    let options = eframe::NativeOptions::default();
+    let result = eframe::run_native(
+        "Auto Clicker",
+        options,
+        Box::new(|_cc| Ok(Box::new(ControlPanel { data }))),
+    );
+    if let Err(error) = result {
+        eprintln!("Error running GUI: {error}");
+    }
+}
+
+#[cfg(feature = "gui")]
+struct ControlPanel {
+    data: Arc<Data>,
+}
+
+#[cfg(feature = "gui")]
+impl eframe::App for ControlPanel {
+    fn update(&mut self, ctx: &eframe::egui::Context, _frame: &mut eframe::Frame) {
+        use eframe::egui;
+
+        egui::CentralPanel::default().show(ctx, |ui| {
+            ui.heading("Auto Clicker");
+
+            let running = if self.data.get_running() { "ON" } else { "OFF" };
+            ui.label(format!("Running: {running}"));
+            ui.label(format!("Target:  {:?}", self.data.get_target_button()));
+            ui.label(format!(
+                "Clicks:  {}",
+                self.data.click_count.load(Ordering::SeqCst)
+            ));
+            ui.label(format!(
+                "Last click interval: {:.1} ms",
+                *self.data.last_click_interval_ms.lock().unwrap()
+            ));
+            ui.label(format!(
+                "Last hold duration:  {:.1} ms",
+                *self.data.last_hold_duration_ms.lock().unwrap()
+            ));
+
+            ui.separator();
+
+            // Editing a slider rewrites the shared params so the clicker re-samples on its next loop
+            let mut params = self.data.get_params();
+            let mut changed = false;
+            changed |= ui
+                .add(
+                    egui::Slider::new(&mut params.click_interval_mean_ms, 0.0..=3000.0)
+                        .text("Click interval mean (ms)"),
+                )
+                .changed();
+            changed |= ui
+                .add(
+                    egui::Slider::new(&mut params.click_interval_sd_ms, 0.0..=1000.0)
+                        .text("Click interval SD (ms)"),
+                )
+                .changed();
+            changed |= ui
+                .add(
+                    egui::Slider::new(&mut params.hold_duration_mean_ms, 0.0..=1000.0)
+                        .text("Hold duration mean (ms)"),
+                )
+                .changed();
+            changed |= ui
+                .add(
+                    egui::Slider::new(&mut params.hold_duration_sd_ms, 0.0..=500.0)
+                        .text("Hold duration SD (ms)"),
+                )
+                .changed();
+            changed |= ui
+                .add(
+                    egui::Slider::new(&mut params.move_stop_distance_px, 0.0..=200.0)
+                        .text("Mouse move threshold (px)"),
+                )
+                .changed();
+
+            if changed {
+                self.data.set_params(params);
+            }
+        });
+
+        // Continuously repaint so the status labels track the clicker thread
+        ctx.request_repaint();
+    }
+}
+
+fn handle_event(event: rdev::Event, data: &Arc<Data>) {
     match event.event_type {
-        rdev::EventType::KeyPress(RUNNING_TOGGLE_KEY) => {
-            let new_running = !data.get_running();
-            data.set_running(new_running, "hotkey pressed");
+        rdev::EventType::KeyPress(CHORD_KEY_A) => data.chord_key_press(0),
+        rdev::EventType::KeyPress(CHORD_KEY_B) => data.chord_key_press(1),
+        rdev::EventType::KeyRelease(CHORD_KEY_A) => data.chord_key_release(0),
+        rdev::EventType::KeyRelease(CHORD_KEY_B) => data.chord_key_release(1),
+        rdev::EventType::KeyPress(key) => {
+            // A bound combo is consumed; anything else is recordable input
+            if !data.handle_key_press(key) && data.get_recording() {
+                data.record_event(event.event_type);
+            }
+        }
+        rdev::EventType::KeyRelease(key) => {
+            data.handle_key_release(key);
+            if data.get_recording() {
+                data.record_event(event.event_type);
+            }
         }
         rdev::EventType::MouseMove { x, y } => {
+            if data.get_recording() {
+                data.record_event(event.event_type);
+            }
+
             if !data.get_running() {
                 return;
             }
@@ -163,13 +813,19 @@ fn handle_event(event: rdev::Event, data: &Data) {
                 let dy = y - initial_y;
                 let dist_sq = dx * dx + dy * dy;
 
-                if dist_sq > MOVE_STOP_DISTANCE_PX * MOVE_STOP_DISTANCE_PX {
+                let threshold = data.get_params().move_stop_distance_px;
+                if dist_sq > threshold * threshold {
                     data.set_running(false, "mouse moved");
                 }
             } else {
                 *pos_guard = Some((x, y));
             }
         }
-        _ => {}
+        _ => {
+            // Capture any other event (button/wheel) while recording
+            if data.get_recording() {
+                data.record_event(event.event_type);
+            }
+        }
     }
 }